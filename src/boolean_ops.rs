@@ -0,0 +1,161 @@
+use crate::clipping::clip_with_options;
+use crate::geometry::{Coord, Polygon};
+
+/// A boolean set operation between two polygons, as provided by Clipper-style libraries.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+/// Decides the result of `op` for two polygons that don't cross at all, from how they
+/// contain one another. A clip-by-window strategy only ever needs to keep or drop the
+/// subject, but boolean ops also need to produce holes (the subtrahend nested as a
+/// negatively-wound ring) and to keep both operands around for a disjoint union/XOR.
+fn no_crossings_result<T: Coord>(op: BooleanOp, a: &Polygon<T>, b: &Polygon<T>) -> Vec<Polygon<T>> {
+    let b_in_a = b.vertexes.first().is_some_and(|v| a.contains_point(v));
+    let a_in_b = a.vertexes.first().is_some_and(|v| b.contains_point(v));
+
+    match op {
+        BooleanOp::Union => {
+            if b_in_a {
+                vec![a.clone()]
+            } else if a_in_b {
+                vec![b.clone()]
+            } else {
+                vec![a.clone(), b.clone()]
+            }
+        }
+        BooleanOp::Intersection => {
+            if b_in_a {
+                vec![b.clone()]
+            } else if a_in_b {
+                vec![a.clone()]
+            } else {
+                vec![]
+            }
+        }
+        BooleanOp::Difference => {
+            if b_in_a {
+                vec![a.clone(), b.reversed()]
+            } else if a_in_b {
+                vec![]
+            } else {
+                vec![a.clone()]
+            }
+        }
+        BooleanOp::SymmetricDifference => {
+            if b_in_a {
+                vec![a.clone(), b.reversed()]
+            } else if a_in_b {
+                vec![b.clone(), a.reversed()]
+            } else {
+                vec![a.clone(), b.clone()]
+            }
+        }
+    }
+}
+
+/// Applies a single boolean `op` between `a` and `b`.
+///
+/// This reuses the Weiler-Atherton intersection-ring machinery, parameterized by operation:
+/// intersection follows the interior on both sides, union follows the exterior, and
+/// difference/XOR flip the traversal direction of the second operand (by feeding it in
+/// reversed) so the subtracted region comes out as a negatively-wound hole ring.
+pub(crate) fn apply<T: Coord>(op: BooleanOp, a: &Polygon<T>, b: &Polygon<T>) -> Vec<Polygon<T>> {
+    match op {
+        BooleanOp::Intersection => clip_with_options(a, b, false, |a, b| no_crossings_result(op, a, b)),
+        BooleanOp::Union => clip_with_options(a, b, true, |a, b| no_crossings_result(op, a, b)),
+        BooleanOp::Difference => {
+            let reversed_b = b.reversed();
+            clip_with_options(a, &reversed_b, true, |subject, _reversed_b| no_crossings_result(op, subject, b))
+        }
+        BooleanOp::SymmetricDifference => {
+            let mut result = apply(BooleanOp::Difference, a, b);
+            result.extend(apply(BooleanOp::Difference, b, a));
+            result
+        }
+    }
+}
+
+/// Unions every polygon in `polygons` together, merging overlapping fill regions into as
+/// few output polygons as possible.
+pub(crate) fn union_all<T: Coord>(polygons: &[Polygon<T>]) -> Vec<Polygon<T>> {
+    let mut merged: Vec<Polygon<T>> = vec![];
+
+    for polygon in polygons {
+        let mut absorbed = polygon.clone();
+        let mut remaining = vec![];
+
+        for existing in merged {
+            let unioned = apply(BooleanOp::Union, &absorbed, &existing);
+            if unioned.len() == 1 {
+                absorbed = unioned.into_iter().next().unwrap();
+            } else {
+                remaining.push(existing);
+            }
+        }
+
+        remaining.push(absorbed);
+        merged = remaining;
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+
+    fn has_vertex(polygon: &Polygon<f64>, x: f64, y: f64) -> bool {
+        polygon.vertexes.iter().any(|v| v.x == x && v.y == y)
+    }
+
+    fn square(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Polygon<f64> {
+        Polygon::new(vec![
+            Point::new(min_x, min_y),
+            Point::new(max_x, min_y),
+            Point::new(max_x, max_y),
+            Point::new(min_x, max_y),
+        ])
+    }
+
+    #[test]
+    fn intersection_of_overlapping_squares() {
+        let a = square(0.0, 0.0, 100.0, 100.0);
+        let b = square(50.0, 50.0, 150.0, 150.0);
+
+        let result = apply(BooleanOp::Intersection, &a, &b);
+
+        assert_eq!(result.len(), 1);
+        assert!(has_vertex(&result[0], 50.0, 50.0));
+        assert!(has_vertex(&result[0], 100.0, 50.0));
+        assert!(has_vertex(&result[0], 100.0, 100.0));
+        assert!(has_vertex(&result[0], 50.0, 100.0));
+    }
+
+    #[test]
+    fn union_of_disjoint_squares_keeps_both() {
+        let a = square(0.0, 0.0, 10.0, 10.0);
+        let b = square(1000.0, 1000.0, 1010.0, 1010.0);
+
+        let result = apply(BooleanOp::Union, &a, &b);
+
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn difference_of_fully_contained_square_leaves_a_hole() {
+        let a = square(0.0, 0.0, 100.0, 100.0);
+        let b = square(25.0, 25.0, 75.0, 75.0);
+
+        let result = apply(BooleanOp::Difference, &a, &b);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].vertexes, a.vertexes);
+        assert_eq!(result[1].vertexes, b.reversed().vertexes);
+    }
+}
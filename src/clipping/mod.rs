@@ -0,0 +1,32 @@
+mod rect_clipper;
+mod sutherland_hodgman;
+mod weiler_atherton;
+
+pub(crate) use rect_clipper::RectClipper;
+pub(crate) use sutherland_hodgman::SutherlandHodgman;
+pub(crate) use weiler_atherton::{clip_with_options, WeilerAtherton};
+
+use crate::geometry::{Coord, Polygon};
+
+/// A strategy for clipping `input_polygon` against `clipping_polygon`.
+///
+/// The result is a set of polygons rather than a single one because some strategies
+/// (e.g. [`WeilerAtherton`]) can split the subject into several disjoint pieces.
+pub(crate) trait ClippingStrategy<T: Coord> {
+    fn clip_polygon(&self, clipping_polygon: &Polygon<T>, input_polygon: &Polygon<T>) -> Vec<Polygon<T>>;
+}
+
+pub(crate) struct PolygonClippingCalculator<'a, T: Coord, C: ClippingStrategy<T>> {
+    strategy: &'a C,
+    _coord: std::marker::PhantomData<T>,
+}
+
+impl<'a, T: Coord, C: ClippingStrategy<T>> PolygonClippingCalculator<'a, T, C> {
+    pub(crate) fn new(strategy: &'a C) -> Self {
+        Self { strategy, _coord: std::marker::PhantomData }
+    }
+
+    pub(crate) fn clip_polygon(&self, polygon: &Polygon<T>, clipping_polygon: &Polygon<T>) -> Vec<Polygon<T>> {
+        self.strategy.clip_polygon(polygon, clipping_polygon)
+    }
+}
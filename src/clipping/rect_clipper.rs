@@ -0,0 +1,144 @@
+use crate::clipping::ClippingStrategy;
+use crate::geometry::{Coord, Point, Polygon};
+
+/// One of the four bounds of an axis-aligned rectangle, in the order `RectClipper` walks
+/// them: left, right, bottom, top.
+#[derive(Clone, Copy)]
+enum Bound<T: Coord> {
+    MinX(T),
+    MaxX(T),
+    MinY(T),
+    MaxY(T),
+}
+
+impl<T: Coord> Bound<T> {
+    /// Whether `point` is on the inside of this bound, using a single comparison instead of
+    /// the general `Line::cross_product` sign test.
+    fn is_inside(&self, point: &Point<T>) -> bool {
+        match *self {
+            Bound::MinX(min_x) => point.x >= min_x,
+            Bound::MaxX(max_x) => point.x <= max_x,
+            Bound::MinY(min_y) => point.y >= min_y,
+            Bound::MaxY(max_y) => point.y <= max_y,
+        }
+    }
+
+    /// Finds where the segment `start -> end` crosses this bound by a single-axis linear
+    /// interpolation, rather than the full 2x2 determinant solve in `Line::intersection`.
+    fn intersection(&self, start: &Point<T>, end: &Point<T>) -> Point<T> {
+        match *self {
+            Bound::MinX(min_x) => {
+                let t = (min_x - start.x) / (end.x - start.x);
+                Point::new(min_x, start.y + (end.y - start.y) * t)
+            }
+            Bound::MaxX(max_x) => {
+                let t = (max_x - start.x) / (end.x - start.x);
+                Point::new(max_x, start.y + (end.y - start.y) * t)
+            }
+            Bound::MinY(min_y) => {
+                let t = (min_y - start.y) / (end.y - start.y);
+                Point::new(start.x + (end.x - start.x) * t, min_y)
+            }
+            Bound::MaxY(max_y) => {
+                let t = (max_y - start.y) / (end.y - start.y);
+                Point::new(start.x + (end.x - start.x) * t, max_y)
+            }
+        }
+    }
+}
+
+/// Clips against an axis-aligned rectangle, as a fast path for the common
+/// viewport/tile-bounds case.
+///
+/// Each of the four bounds only needs a single comparison to classify a point as inside or
+/// outside, and intersections are found by a 1-D `lerp` along the single crossing axis,
+/// instead of the general `cross_product`/`Line::intersection` machinery `SutherlandHodgman`
+/// needs to support an arbitrary convex clip polygon.
+pub(crate) struct RectClipper<T: Coord> {
+    bounds: [Bound<T>; 4],
+}
+
+impl<T: Coord> RectClipper<T> {
+    pub(crate) fn new(min_x: T, min_y: T, max_x: T, max_y: T) -> Self {
+        RectClipper {
+            bounds: [Bound::MinX(min_x), Bound::MaxX(max_x), Bound::MinY(min_y), Bound::MaxY(max_y)],
+        }
+    }
+}
+
+impl<T: Coord> ClippingStrategy<T> for RectClipper<T> {
+    fn clip_polygon(&self, _clipping_polygon: &Polygon<T>, input_polygon: &Polygon<T>) -> Vec<Polygon<T>> {
+        let mut output_polygon = input_polygon.vertexes.clone();
+
+        for bound in &self.bounds {
+            if output_polygon.is_empty() {
+                break;
+            }
+
+            let mut new_vertexes = vec![];
+            for j in 0..output_polygon.len() {
+                let start_point = output_polygon[j];
+                let end_point = output_polygon[(j + 1) % output_polygon.len()];
+
+                let start_inside = bound.is_inside(&start_point);
+                let end_inside = bound.is_inside(&end_point);
+
+                if start_inside && end_inside {
+                    new_vertexes.push(end_point);
+                } else if start_inside && !end_inside {
+                    new_vertexes.push(bound.intersection(&start_point, &end_point));
+                } else if !start_inside && end_inside {
+                    new_vertexes.push(bound.intersection(&start_point, &end_point));
+                    new_vertexes.push(end_point);
+                }
+            }
+
+            output_polygon = new_vertexes;
+        }
+
+        if output_polygon.is_empty() {
+            return vec![];
+        }
+
+        vec![Polygon::new(output_polygon)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clips_a_square_to_the_viewport() {
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(100.0, 0.0),
+            Point::new(100.0, 100.0),
+            Point::new(0.0, 100.0),
+        ]);
+
+        let rect_clipper = RectClipper::new(50.0, 50.0, 150.0, 150.0);
+        let result = rect_clipper.clip_polygon(&square, &square);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].vertexes,
+            vec![Point::new(100.0, 100.0), Point::new(50.0, 100.0), Point::new(50.0, 50.0), Point::new(100.0, 50.0)]
+        );
+    }
+
+    #[test]
+    fn disjoint_square_clips_to_nothing() {
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+        ]);
+
+        let rect_clipper = RectClipper::new(50.0, 50.0, 150.0, 150.0);
+        let result = rect_clipper.clip_polygon(&square, &square);
+
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::clipping::ClippingStrategy;
+use crate::geometry::{Coord, Line, Point, Polygon};
+
+#[derive(Debug)]
+enum PointPosition<T: Coord> {
+    Inside(Point<T>),
+    Outside(Point<T>),
+}
+
+impl<T: Coord> PointPosition<T> {
+    fn is_inside(point: Point<T>, line: &Line<T>) -> Self {
+        if line.is_inside(&point) {
+            PointPosition::Inside(point)
+        } else {
+            PointPosition::Outside(point)
+        }
+    }
+}
+
+struct PointPositions<T: Coord> {
+    start: PointPosition<T>,
+    end: PointPosition<T>,
+}
+
+impl<T: Coord> PointPositions<T> {
+    fn new(start: PointPosition<T>, end: PointPosition<T>) -> Self {
+        PointPositions { start, end }
+    }
+
+    fn calculate_vertexes(self, line: &Line<T>) -> Vec<Point<T>> {
+        let mut vertexes = vec![];
+        match (self.start, self.end) {
+            (PointPosition::Inside(_), PointPosition::Inside(end)) => {
+                vertexes.push(end);
+            }
+            (PointPosition::Inside(start), PointPosition::Outside(end)) => {
+                Self::calculate_intersection(
+                    line,
+                    &Line::new(start, end),
+                    &mut vertexes,
+                );
+            }
+            (PointPosition::Outside(start), PointPosition::Inside(end)) => {
+                let intersection_line = Line::new(start, end);
+                Self::calculate_intersection(
+                    line,
+                    &intersection_line,
+                    &mut vertexes,
+                );
+
+                vertexes.push(intersection_line.end);
+            }
+            _ => {}
+        }
+
+        vertexes
+    }
+
+    fn calculate_intersection(
+        line: &Line<T>,
+        intersection_line: &Line<T>,
+        vertexes: &mut Vec<Point<T>>,
+    ) {
+        let intersection = line.intersection(&intersection_line);
+        if let Some(intersection) = intersection {
+            vertexes.push(intersection);
+        }
+    }
+}
+
+pub(crate) struct SutherlandHodgman;
+
+impl SutherlandHodgman {
+    fn remove_duplicated_points<T: Coord>(output_polygon: Vec<Point<T>>) -> Vec<Point<T>> {
+        let mut unique_points: Vec<Point<T>> = Vec::new();
+        for point in output_polygon {
+            if unique_points.last() != Some(&point) {
+                unique_points.push(point);
+            }
+        }
+
+        unique_points
+    }
+}
+
+impl<T: Coord> ClippingStrategy<T> for SutherlandHodgman {
+    fn clip_polygon(&self, clipping_polygon: &Polygon<T>, input_polygon: &Polygon<T>) -> Vec<Polygon<T>> {
+        let mut output_polygon = input_polygon.vertexes.clone();
+
+        for i in 0..clipping_polygon.vertexes.len() {
+            let mut new_vertexes = vec![];
+            let clipping_start = &clipping_polygon.vertexes[i];
+            let clipping_end = &clipping_polygon.vertexes[(i + 1) % clipping_polygon.vertexes.len()];
+            let clipping_line = Line::new(*clipping_start, *clipping_end);
+
+            for j in 0..output_polygon.len() {
+                let start_point = &output_polygon[j];
+                let end_point = &output_polygon[(j + 1) % output_polygon.len()];
+
+                let current_position = PointPosition::is_inside(*start_point, &clipping_line);
+                let next_position = PointPosition::is_inside(*end_point, &clipping_line);
+
+                PointPositions::new(current_position, next_position)
+                    .calculate_vertexes(&clipping_line)
+                    .into_iter()
+                    .for_each(|vertex| new_vertexes.push(vertex));
+            }
+
+            output_polygon = new_vertexes;
+        }
+
+        if output_polygon.is_empty() {
+            return vec![];
+        }
+
+        let unique_points = Self::remove_duplicated_points(output_polygon);
+
+        vec![Polygon::new(unique_points)]
+    }
+}
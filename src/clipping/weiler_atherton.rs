@@ -0,0 +1,325 @@
+use crate::clipping::ClippingStrategy;
+use crate::geometry::{Coord, Line, Point, Polygon};
+
+/// A vertex in one of the two intersection rings built up while clipping.
+///
+/// Plain vertices just carry their point; intersection vertices additionally carry a link
+/// to their twin node in the other ring and whether the crossing is a walk-start ("entering")
+/// or not.
+#[derive(Clone, Copy)]
+struct RingNode<T: Coord> {
+    point: Point<T>,
+    is_intersection: bool,
+    entering: bool,
+    neighbor: Option<usize>,
+}
+
+impl<T: Coord> RingNode<T> {
+    fn vertex(point: Point<T>) -> Self {
+        RingNode { point, is_intersection: false, entering: false, neighbor: None }
+    }
+}
+
+/// A single subject/clip edge crossing, identified by `id` so the matching node can be
+/// linked in both rings once they have been built.
+struct Crossing<T: Coord> {
+    id: usize,
+    subject_edge: usize,
+    t_subject: T,
+    clip_edge: usize,
+    t_clip: T,
+    point: Point<T>,
+}
+
+/// Intersects segment `a` against segment `b`, returning the parametric position along each
+/// (`t` for `a`, `u` for `b`) when the crossing lies within both segments.
+fn segment_intersection<T: Coord>(a: &Line<T>, b: &Line<T>) -> Option<(T, T, Point<T>)> {
+    let r = Point::new(a.end.x - a.start.x, a.end.y - a.start.y);
+    let s = Point::new(b.end.x - b.start.x, b.end.y - b.start.y);
+    let rxs = r.x * s.y - r.y * s.x;
+
+    if rxs == T::ZERO {
+        return None;
+    }
+
+    let qp = Point::new(b.start.x - a.start.x, b.start.y - a.start.y);
+    let t = (qp.x * s.y - qp.y * s.x) / rxs;
+    let u = (qp.x * r.y - qp.y * r.x) / rxs;
+
+    if t < T::ZERO || t > T::ONE || u < T::ZERO || u > T::ONE {
+        return None;
+    }
+
+    Some((t, u, Point::new(a.start.x + r.x * t, a.start.y + r.y * t)))
+}
+
+fn lerp<T: Coord>(start: Point<T>, end: Point<T>, t: T) -> Point<T> {
+    Point::new(start.x + (end.x - start.x) * t, start.y + (end.y - start.y) * t)
+}
+
+/// Walks `subject_ring`/`clip_ring`, starting at `start_in_subject`, alternating rings at
+/// every crossing until it returns to the starting node.
+fn walk<T: Coord>(
+    start_in_subject: usize,
+    subject_ring: &[RingNode<T>],
+    clip_ring: &[RingNode<T>],
+    subject_visited: &mut [bool],
+    clip_visited: &mut [bool],
+) -> Vec<Point<T>> {
+    let mut points = vec![];
+    let mut in_subject = true;
+    let mut index = start_in_subject;
+
+    loop {
+        let node = if in_subject { subject_ring[index] } else { clip_ring[index] };
+        points.push(node.point);
+
+        if node.is_intersection {
+            if in_subject {
+                subject_visited[index] = true;
+            } else {
+                clip_visited[index] = true;
+            }
+        }
+
+        // Step to the next node in the ring we're currently following.
+        index = if in_subject {
+            (index + 1) % subject_ring.len()
+        } else {
+            (index + 1) % clip_ring.len()
+        };
+
+        // Landing on a crossing hops to its twin in the other ring; the break check below
+        // runs after this so closing the loop via a hop is caught too.
+        let landed = if in_subject { subject_ring[index] } else { clip_ring[index] };
+        if landed.is_intersection {
+            if let Some(neighbor) = landed.neighbor {
+                in_subject = !in_subject;
+                index = neighbor;
+            }
+        }
+
+        if in_subject && index == start_in_subject {
+            break;
+        }
+    }
+
+    points
+}
+
+/// Runs the Weiler-Atherton ring machinery between `subject_polygon` and `clip_polygon`.
+///
+/// `invert_entering` flips which subject/clip crossings count as walk starts: `false` traces
+/// the region interior to both polygons (intersection), `true` traces the region exterior to
+/// `clip_polygon` (used to build union and difference out of the same machinery). When the
+/// two polygons don't cross at all, `no_crossings` decides the result from their containment,
+/// since that varies by operation (a clip window keeps the subject only when the subject lies
+/// inside it, while e.g. a difference of disjoint polygons keeps the subject and one whose
+/// subject lies inside the other polygon keeps nothing).
+///
+/// The subject is normalized to counter-clockwise internally, since forward-forward ring
+/// traversal needs a known orientation to trace the correct region. `clip_polygon` is used
+/// as given: callers that want plain clip-window or union/intersection semantics should pass
+/// it counter-clockwise too, while a boolean difference deliberately passes it clockwise
+/// (reversed) to flip which side of it the walk treats as "outside".
+pub(crate) fn clip_with_options<T: Coord>(
+    subject_polygon: &Polygon<T>,
+    clip_polygon: &Polygon<T>,
+    invert_entering: bool,
+    no_crossings: impl FnOnce(&Polygon<T>, &Polygon<T>) -> Vec<Polygon<T>>,
+) -> Vec<Polygon<T>> {
+    let subject_vertexes = subject_polygon.to_ccw().vertexes;
+    let clip_vertexes = clip_polygon.vertexes.clone();
+
+    if subject_vertexes.len() < 3 || clip_vertexes.len() < 3 {
+        return vec![];
+    }
+
+    let subject_edges = subject_vertexes.len();
+    let clip_edges = clip_vertexes.len();
+
+    let mut crossings: Vec<Crossing<T>> = vec![];
+    for i in 0..subject_edges {
+        let subject_line = Line::new(subject_vertexes[i], subject_vertexes[(i + 1) % subject_edges]);
+        for j in 0..clip_edges {
+            let clip_line = Line::new(clip_vertexes[j], clip_vertexes[(j + 1) % clip_edges]);
+
+            if let Some((t, u, point)) = segment_intersection(&subject_line, &clip_line) {
+                crossings.push(Crossing {
+                    id: crossings.len(),
+                    subject_edge: i,
+                    t_subject: t,
+                    clip_edge: j,
+                    t_clip: u,
+                    point,
+                });
+            }
+        }
+    }
+
+    if crossings.is_empty() {
+        return no_crossings(subject_polygon, clip_polygon);
+    }
+
+    let mut subject_ring = vec![];
+    let mut subject_node_of_id = vec![usize::MAX; crossings.len()];
+    for (i, &vertex) in subject_vertexes.iter().enumerate() {
+        subject_ring.push(RingNode::vertex(vertex));
+
+        let mut hits: Vec<&Crossing<T>> = crossings.iter().filter(|c| c.subject_edge == i).collect();
+        hits.sort_by(|a, b| a.t_subject.partial_cmp(&b.t_subject).unwrap());
+
+        for crossing in hits {
+            let mut node = RingNode::vertex(crossing.point);
+            node.is_intersection = true;
+            subject_node_of_id[crossing.id] = subject_ring.len();
+            subject_ring.push(node);
+        }
+    }
+
+    let mut clip_ring = vec![];
+    let mut clip_node_of_id = vec![usize::MAX; crossings.len()];
+    for (j, &vertex) in clip_vertexes.iter().enumerate() {
+        clip_ring.push(RingNode::vertex(vertex));
+
+        let mut hits: Vec<&Crossing<T>> = crossings.iter().filter(|c| c.clip_edge == j).collect();
+        hits.sort_by(|a, b| a.t_clip.partial_cmp(&b.t_clip).unwrap());
+
+        for crossing in hits {
+            let mut node = RingNode::vertex(crossing.point);
+            node.is_intersection = true;
+            clip_node_of_id[crossing.id] = clip_ring.len();
+            clip_ring.push(node);
+        }
+    }
+
+    for crossing in &crossings {
+        let subject_index = subject_node_of_id[crossing.id];
+        let clip_index = clip_node_of_id[crossing.id];
+        subject_ring[subject_index].neighbor = Some(clip_index);
+        clip_ring[clip_index].neighbor = Some(subject_index);
+    }
+
+    // Determine entering/leaving for each subject-ring crossing by sampling the subject edge
+    // just past it and testing the sample against the clip polygon (even-odd), which stays
+    // correct for concave clip windows.
+    for i in 0..subject_edges {
+        let edge_start = subject_vertexes[i];
+        let edge_end = subject_vertexes[(i + 1) % subject_edges];
+
+        let mut hits: Vec<&Crossing<T>> = crossings.iter().filter(|c| c.subject_edge == i).collect();
+        hits.sort_by(|a, b| a.t_subject.partial_cmp(&b.t_subject).unwrap());
+
+        for (position, crossing) in hits.iter().enumerate() {
+            let next_t = hits.get(position + 1).map(|c| c.t_subject).unwrap_or(T::ONE);
+            let sample_t = crossing.t_subject + (next_t - crossing.t_subject) / (T::ONE + T::ONE);
+            let sample_point = lerp(edge_start, edge_end, sample_t);
+
+            let entering = clip_polygon.contains_point(&sample_point) != invert_entering;
+            subject_ring[subject_node_of_id[crossing.id]].entering = entering;
+        }
+    }
+
+    let mut subject_visited = vec![false; subject_ring.len()];
+    let mut clip_visited = vec![false; clip_ring.len()];
+    let mut output = vec![];
+
+    for index in 0..subject_ring.len() {
+        if subject_ring[index].is_intersection && subject_ring[index].entering && !subject_visited[index] {
+            let points = walk(index, &subject_ring, &clip_ring, &mut subject_visited, &mut clip_visited);
+            if points.len() >= 3 {
+                output.push(Polygon::new(points));
+            }
+        }
+    }
+
+    output
+}
+
+/// Clips arbitrary simple polygons, including concave clip windows, using the
+/// Weiler-Atherton algorithm.
+///
+/// Unlike [`super::SutherlandHodgman`] this can split the subject polygon into several
+/// disjoint output polygons, since a concave clip window may carve the subject into multiple
+/// pieces.
+pub(crate) struct WeilerAtherton;
+
+impl<T: Coord> ClippingStrategy<T> for WeilerAtherton {
+    fn clip_polygon(&self, clipping_polygon: &Polygon<T>, input_polygon: &Polygon<T>) -> Vec<Polygon<T>> {
+        clip_with_options(input_polygon, &clipping_polygon.to_ccw(), false, |subject, clip| {
+            let subject_in_clip = subject.vertexes.first().is_some_and(|v| clip.contains_point(v));
+            if subject_in_clip { vec![Polygon::new(subject.vertexes.clone())] } else { vec![] }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn has_vertex<T: Coord>(polygon: &Polygon<T>, x: T, y: T) -> bool {
+        polygon.vertexes.iter().any(|v| v.x == x && v.y == y)
+    }
+
+    /// A concave "staple" window: full-width at the bottom (y 0..3), then two towers (x 0..8
+    /// and x 12..20) rising to y 10, with a notch cut out between them.
+    fn staple_window() -> Polygon<f64> {
+        Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(20.0, 0.0),
+            Point::new(20.0, 10.0),
+            Point::new(12.0, 10.0),
+            Point::new(12.0, 3.0),
+            Point::new(8.0, 3.0),
+            Point::new(8.0, 10.0),
+            Point::new(0.0, 10.0),
+        ])
+    }
+
+    #[test]
+    fn splits_subject_straddling_a_concave_notch() {
+        let subject = Polygon::new(vec![
+            Point::new(4.0, 4.0),
+            Point::new(16.0, 4.0),
+            Point::new(16.0, 6.0),
+            Point::new(4.0, 6.0),
+        ]);
+
+        let result = WeilerAtherton.clip_polygon(&staple_window(), &subject);
+
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().any(|p| {
+            has_vertex(p, 4.0, 4.0) && has_vertex(p, 8.0, 4.0) && has_vertex(p, 8.0, 6.0) && has_vertex(p, 4.0, 6.0)
+        }));
+        assert!(result.iter().any(|p| {
+            has_vertex(p, 12.0, 4.0) && has_vertex(p, 16.0, 4.0) && has_vertex(p, 16.0, 6.0) && has_vertex(p, 12.0, 6.0)
+        }));
+    }
+
+    #[test]
+    fn disjoint_subject_clips_to_nothing() {
+        let subject = Polygon::new(vec![
+            Point::new(1000.0, 1000.0),
+            Point::new(1010.0, 1000.0),
+            Point::new(1010.0, 1010.0),
+            Point::new(1000.0, 1010.0),
+        ]);
+
+        let result = WeilerAtherton.clip_polygon(&staple_window(), &subject);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn fully_contained_subject_is_returned_unchanged() {
+        let subject = Polygon::new(vec![
+            Point::new(2.0, 2.0),
+            Point::new(4.0, 2.0),
+            Point::new(4.0, 4.0),
+            Point::new(2.0, 4.0),
+        ]);
+
+        let result = WeilerAtherton.clip_polygon(&staple_window(), &subject);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].vertexes, subject.vertexes);
+    }
+}
@@ -0,0 +1,185 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A numeric coordinate type usable as the `x`/`y` components of a [`Point`].
+///
+/// This abstracts over the arithmetic `cross_product` and `intersection` rely on so the
+/// clipping algorithms can run over floating point coordinates. Every clipping strategy in
+/// this crate also carries its parametric crossing position (`t`) in `T`, so `T` has to stay
+/// fractional end-to-end; an integer impl would truncate every `t` to `0` or `1` and silently
+/// corrupt crossing positions. Don't add one without first giving `t` its own always-fractional
+/// type.
+pub(crate) trait Coord:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+impl Coord for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+}
+
+impl Coord for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Point<T: Coord> {
+    pub(crate) x: T,
+    pub(crate) y: T,
+}
+
+impl<T: Coord> Eq for Point<T> {}
+
+impl<T: Coord> PartialEq for Point<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<T: Coord> Point<T> {
+    pub(crate) fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Line<T: Coord> {
+    pub(crate) start: Point<T>,
+    pub(crate) end: Point<T>,
+}
+
+impl<T: Coord> Line<T> {
+    pub(crate) fn new(start: Point<T>, end: Point<T>) -> Self {
+        Line { start, end }
+    }
+
+    pub(crate) fn cross_product(&self, point: &Point<T>) -> T {
+        let vector_edge = Point::new(self.end.x - self.start.x, self.end.y - self.start.y);
+        let vector_vertex = Point::new(point.x - self.start.x, point.y - self.start.y);
+
+        vector_edge.x * vector_vertex.y - vector_edge.y * vector_vertex.x
+    }
+
+    pub(crate) fn is_inside(&self, point: &Point<T>) -> bool {
+        self.cross_product(point) >= T::ZERO
+    }
+
+    /// Finds where `other` crosses `self`, treating `self` as an infinite line rather than
+    /// a finite segment.
+    ///
+    /// This is what Sutherland-Hodgman needs: the clip edge (`self`) only defines which
+    /// half-plane is "inside", so a crossing can legitimately fall outside its finite
+    /// bounding box without being invalid. Requiring it to also land inside `self`'s segment
+    /// bounds (as a plain segment-segment intersection would) silently drops valid vertices
+    /// whenever the subject polygon is large relative to the clip window.
+    ///
+    /// Solves for the parameter `t` along `other` only, via `t = cross(self_dir, self.start
+    /// - other.start) / cross(self_dir, other_dir)`, and accepts it when `0 <= t <= 1`.
+    pub(crate) fn intersection(&self, other: &Line<T>) -> Option<Point<T>> {
+        let self_dir = Point::new(self.end.x - self.start.x, self.end.y - self.start.y);
+        let other_dir = Point::new(other.end.x - other.start.x, other.end.y - other.start.y);
+        let determinant = self_dir.x * other_dir.y - self_dir.y * other_dir.x;
+
+        // PARALLEL LINES
+        if determinant == T::ZERO {
+            return None;
+        }
+
+        let start_diff = Point::new(self.start.x - other.start.x, self.start.y - other.start.y);
+        let t = (self_dir.x * start_diff.y - self_dir.y * start_diff.x) / determinant;
+
+        if t < T::ZERO || t > T::ONE {
+            return None;
+        }
+
+        Some(Point::new(other.start.x + other_dir.x * t, other.start.y + other_dir.y * t))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Polygon<T: Coord> {
+    pub(crate) vertexes: Vec<Point<T>>,
+}
+
+impl<T: Coord> Polygon<T> {
+    pub(crate) fn new(vertexes: Vec<Point<T>>) -> Self {
+        Polygon { vertexes }
+    }
+
+    /// Reverses winding order, turning an outer ring into a hole ring and vice versa.
+    pub(crate) fn reversed(&self) -> Self {
+        let mut vertexes = self.vertexes.clone();
+        vertexes.reverse();
+        Polygon { vertexes }
+    }
+
+    fn signed_area(&self) -> T {
+        let n = self.vertexes.len();
+        let mut area = T::ZERO;
+        for i in 0..n {
+            let a = self.vertexes[i];
+            let b = self.vertexes[(i + 1) % n];
+            area = area + (a.x * b.y - b.x * a.y);
+        }
+
+        area
+    }
+
+    /// Returns this polygon wound counter-clockwise, reversing it first if needed.
+    pub(crate) fn to_ccw(&self) -> Self {
+        if self.signed_area() < T::ZERO {
+            self.reversed()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Even-odd point-in-polygon test, correct for convex and concave rings alike.
+    pub(crate) fn contains_point(&self, point: &Point<T>) -> bool {
+        let vertexes = &self.vertexes;
+        let n = vertexes.len();
+        let mut inside = false;
+        let mut j = n - 1;
+
+        for i in 0..n {
+            let vi = vertexes[i];
+            let vj = vertexes[j];
+
+            if (vi.y > point.y) != (vj.y > point.y)
+                && point.x < (vj.x - vi.x) * (point.y - vi.y) / (vj.y - vi.y) + vi.x
+            {
+                inside = !inside;
+            }
+
+            j = i;
+        }
+
+        inside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersection_treats_self_as_an_infinite_line() {
+        // The clip edge only spans y in [0, 1], but the crossing with `other` (y = 10) must
+        // still be accepted since `self` is the infinite line x = 0, not the finite segment.
+        let clip_edge = Line::new(Point::new(0.0, 0.0), Point::new(0.0, 1.0));
+        let other = Line::new(Point::new(-5.0, 10.0), Point::new(5.0, 10.0));
+
+        let crossing = clip_edge.intersection(&other).expect("line should cross the subject edge");
+        assert_eq!(crossing.x, 0.0);
+        assert_eq!(crossing.y, 10.0);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::geometry::{Coord, Line, Point, Polygon};
+
+/// One segment of a [`Path`], running from an implicit current point (the path's `start`, or
+/// the previous segment's `end`) to `end`.
+///
+/// Keeping curves as `Cubic` rather than flattening them to line segments up front is the
+/// point of this module: a clipped glyph or icon outline should stay smooth.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Segment<T: Coord> {
+    Line { end: Point<T> },
+    Cubic { control1: Point<T>, control2: Point<T>, end: Point<T> },
+}
+
+impl<T: Coord> Segment<T> {
+    pub(crate) fn end(&self) -> Point<T> {
+        match *self {
+            Segment::Line { end } => end,
+            Segment::Cubic { end, .. } => end,
+        }
+    }
+}
+
+/// An open path made of straight and curved segments, such as a glyph or vector-icon outline.
+#[derive(Debug, Clone)]
+pub(crate) struct Path<T: Coord> {
+    pub(crate) start: Point<T>,
+    pub(crate) segments: Vec<Segment<T>>,
+}
+
+impl<T: Coord> Path<T> {
+    pub(crate) fn new(start: Point<T>, segments: Vec<Segment<T>>) -> Self {
+        Path { start, segments }
+    }
+}
+
+fn lerp<T: Coord>(a: Point<T>, b: Point<T>, t: T) -> Point<T> {
+    Point::new(a.x + (b.x - a.x) * t, a.y + (b.y - a.y) * t)
+}
+
+/// Evaluates the cubic `p0..p3` at `t` via de Casteljau's algorithm (repeated `lerp`), rather
+/// than expanding the Bernstein polynomial.
+fn cubic_point<T: Coord>(p0: Point<T>, p1: Point<T>, p2: Point<T>, p3: Point<T>, t: T) -> Point<T> {
+    let a = lerp(p0, p1, t);
+    let b = lerp(p1, p2, t);
+    let c = lerp(p2, p3, t);
+
+    lerp(lerp(a, b, t), lerp(b, c, t), t)
+}
+
+/// Splits the cubic `p0..p3` at `t` via de Casteljau's algorithm, returning the two cubics
+/// `(p0..t)` and `(t..p3)` that together retrace the original curve exactly.
+fn de_casteljau_split<T: Coord>(
+    p0: Point<T>,
+    p1: Point<T>,
+    p2: Point<T>,
+    p3: Point<T>,
+    t: T,
+) -> ((Point<T>, Point<T>, Point<T>, Point<T>), (Point<T>, Point<T>, Point<T>, Point<T>)) {
+    let a = lerp(p0, p1, t);
+    let b = lerp(p1, p2, t);
+    let c = lerp(p2, p3, t);
+    let d = lerp(a, b, t);
+    let e = lerp(b, c, t);
+    let f = lerp(d, e, t);
+
+    ((p0, a, d, f), (f, e, c, p3))
+}
+
+/// Extracts the control points tracing the sub-range `[t0, t1]` of the cubic `p0..p3`, via two
+/// successive [`de_casteljau_split`] calls.
+fn sub_curve<T: Coord>(
+    p0: Point<T>,
+    p1: Point<T>,
+    p2: Point<T>,
+    p3: Point<T>,
+    t0: T,
+    t1: T,
+) -> (Point<T>, Point<T>, Point<T>, Point<T>) {
+    let (_, right) = de_casteljau_split(p0, p1, p2, p3, t0);
+    let local_t1 = (t1 - t0) / (T::ONE - t0);
+    let (left, _) = de_casteljau_split(right.0, right.1, right.2, right.3, local_t1);
+
+    left
+}
+
+const ROOT_SEARCH_SAMPLES: usize = 32;
+const BISECTION_STEPS: usize = 40;
+
+/// Finds the parameters in `[0, 1]` where the cubic `p0..p3` crosses `clip_line`'s infinite
+/// extension, as `Line::cross_product` changes sign.
+///
+/// The curve's signed distance to the line is itself cubic in `t`, so it can cross zero up to
+/// three times. Rather than solving that cubic directly (which would need `sqrt`/`cbrt`, and
+/// `Coord` provides neither), this samples densely enough to bracket every sign change and
+/// bisects each bracket to `t`.
+fn find_crossings<T: Coord>(clip_line: &Line<T>, p0: Point<T>, p1: Point<T>, p2: Point<T>, p3: Point<T>) -> Vec<T> {
+    let mut sample_count = T::ZERO;
+    for _ in 0..ROOT_SEARCH_SAMPLES {
+        sample_count = sample_count + T::ONE;
+    }
+    let step = T::ONE / sample_count;
+
+    let signed_distance = |t: T| clip_line.cross_product(&cubic_point(p0, p1, p2, p3, t));
+
+    let mut samples = vec![T::ZERO];
+    let mut t = T::ZERO;
+    for _ in 0..ROOT_SEARCH_SAMPLES {
+        t = t + step;
+        samples.push(t);
+    }
+
+    let mut roots = vec![];
+    for window in samples.windows(2) {
+        let (mut lo, hi) = (window[0], window[1]);
+        let lo_val = signed_distance(lo);
+
+        if lo_val == T::ZERO {
+            roots.push(lo);
+            continue;
+        }
+
+        let lo_positive = lo_val > T::ZERO;
+        let hi_positive = signed_distance(hi) > T::ZERO;
+        if lo_positive == hi_positive {
+            continue;
+        }
+
+        let mut hi = hi;
+        for _ in 0..BISECTION_STEPS {
+            let mid = (lo + hi) / (T::ONE + T::ONE);
+            if (signed_distance(mid) > T::ZERO) == lo_positive {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        roots.push((lo + hi) / (T::ONE + T::ONE));
+    }
+
+    roots
+}
+
+/// Clips `path` against every edge of `clip_polygon` in turn, the same edge-at-a-time loop
+/// [`crate::clipping::SutherlandHodgman`] runs over a polygon's vertexes, reusing the same
+/// `Line::cross_product`/`is_inside`/`intersection` primitives for straight segments.
+///
+/// Unlike a closed polygon, a clipped open path can be cut into several disjoint pieces by a
+/// single clip edge (every inside/outside transition starts or ends one), so the result is a
+/// set of paths rather than one.
+pub(crate) fn clip_path<T: Coord>(path: &Path<T>, clip_polygon: &Polygon<T>) -> Vec<Path<T>> {
+    let mut current_paths = vec![path.clone()];
+
+    for i in 0..clip_polygon.vertexes.len() {
+        let clip_start = clip_polygon.vertexes[i];
+        let clip_end = clip_polygon.vertexes[(i + 1) % clip_polygon.vertexes.len()];
+        let clip_line = Line::new(clip_start, clip_end);
+
+        current_paths = current_paths.iter().flat_map(|p| clip_path_against_line(p, &clip_line)).collect();
+    }
+
+    current_paths
+}
+
+/// Clips `path` against a single clip edge, treated as an infinite line (as
+/// [`Line::intersection`] now does for straight segments).
+fn clip_path_against_line<T: Coord>(path: &Path<T>, clip_line: &Line<T>) -> Vec<Path<T>> {
+    let mut output = vec![];
+    let mut current_point = path.start;
+    let mut current_inside = clip_line.is_inside(&current_point);
+    let mut building: Option<Path<T>> = current_inside.then(|| Path::new(current_point, vec![]));
+
+    for segment in &path.segments {
+        match *segment {
+            Segment::Line { end } => {
+                let end_inside = clip_line.is_inside(&end);
+
+                match (current_inside, end_inside) {
+                    (true, true) => {
+                        if let Some(b) = building.as_mut() {
+                            b.segments.push(Segment::Line { end });
+                        }
+                    }
+                    (true, false) => {
+                        if let Some(point) = clip_line.intersection(&Line::new(current_point, end)) {
+                            if let Some(mut b) = building.take() {
+                                b.segments.push(Segment::Line { end: point });
+                                output.push(b);
+                            }
+                        }
+                    }
+                    (false, true) => {
+                        if let Some(point) = clip_line.intersection(&Line::new(current_point, end)) {
+                            building = Some(Path::new(point, vec![Segment::Line { end }]));
+                        }
+                    }
+                    (false, false) => {}
+                }
+
+                current_point = end;
+                current_inside = end_inside;
+            }
+            Segment::Cubic { control1, control2, end } => {
+                let (p0, p1, p2, p3) = (current_point, control1, control2, end);
+
+                let mut boundaries = find_crossings(clip_line, p0, p1, p2, p3);
+                boundaries.push(T::ZERO);
+                boundaries.push(T::ONE);
+                boundaries.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+                for window in boundaries.windows(2) {
+                    let (t0, t1) = (window[0], window[1]);
+                    if t0 == t1 {
+                        continue;
+                    }
+
+                    let midpoint = cubic_point(p0, p1, p2, p3, (t0 + t1) / (T::ONE + T::ONE));
+                    let (q0, q1, q2, q3) = sub_curve(p0, p1, p2, p3, t0, t1);
+
+                    if clip_line.is_inside(&midpoint) {
+                        let b = building.get_or_insert_with(|| Path::new(q0, vec![]));
+                        b.segments.push(Segment::Cubic { control1: q1, control2: q2, end: q3 });
+                    } else if let Some(b) = building.take() {
+                        output.push(b);
+                    }
+                }
+
+                current_point = p3;
+                current_inside = clip_line.is_inside(&p3);
+            }
+        }
+    }
+
+    if let Some(b) = building {
+        output.push(b);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clips_a_straight_segment_to_the_window() {
+        let window = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+        ]);
+
+        let path = Path::new(Point::new(-5.0, 5.0), vec![Segment::Line { end: Point::new(15.0, 5.0) }]);
+
+        let result = clip_path(&path, &window);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].start, Point::new(0.0, 5.0));
+        assert_eq!(result[0].segments.len(), 1);
+        assert_eq!(result[0].segments[0].end(), Point::new(10.0, 5.0));
+    }
+
+    #[test]
+    fn path_entirely_outside_the_window_disappears() {
+        let window = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(10.0, 0.0),
+            Point::new(10.0, 10.0),
+            Point::new(0.0, 10.0),
+        ]);
+
+        let path = Path::new(Point::new(100.0, 100.0), vec![Segment::Line { end: Point::new(200.0, 100.0) }]);
+
+        let result = clip_path(&path, &window);
+        assert!(result.is_empty());
+    }
+}
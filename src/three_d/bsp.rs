@@ -0,0 +1,162 @@
+use crate::geometry::Coord;
+use crate::three_d::{Plane, Point3, Polygon3, SutherlandHodgman3D};
+
+/// Where a polygon falls relative to a splitting plane.
+enum Side {
+    Front,
+    Back,
+    Coplanar,
+    Straddling,
+}
+
+fn classify<T: Coord>(polygon: &Polygon3<T>, plane: &Plane<T>) -> Side {
+    let mut has_front = false;
+    let mut has_back = false;
+
+    for vertex in &polygon.vertexes {
+        let distance = plane.signed_distance(vertex);
+        if distance > T::ZERO {
+            has_front = true;
+        } else if distance < T::ZERO {
+            has_back = true;
+        }
+    }
+
+    match (has_front, has_back) {
+        (false, false) => Side::Coplanar,
+        (true, false) => Side::Front,
+        (false, true) => Side::Back,
+        (true, true) => Side::Straddling,
+    }
+}
+
+struct BspNode<T: Coord> {
+    plane: Plane<T>,
+    // Coplanar polygons facing the same direction as `plane`'s normal.
+    coplanar_front: Vec<Polygon3<T>>,
+    // Coplanar polygons facing the opposite direction.
+    coplanar_back: Vec<Polygon3<T>>,
+    front: Option<Box<BspNode<T>>>,
+    back: Option<Box<BspNode<T>>>,
+}
+
+impl<T: Coord> BspNode<T> {
+    fn build(mut polygons: Vec<Polygon3<T>>) -> Option<BspNode<T>> {
+        if polygons.is_empty() {
+            return None;
+        }
+
+        let splitter = polygons.remove(0);
+        let plane = Plane::from_polygon(&splitter);
+
+        let mut coplanar_front = vec![splitter];
+        let mut coplanar_back = vec![];
+        let mut front_polygons = vec![];
+        let mut back_polygons = vec![];
+
+        for polygon in polygons {
+            match classify(&polygon, &plane) {
+                Side::Coplanar => {
+                    let facing_same_direction = plane.normal.dot(&Plane::from_polygon(&polygon).normal) >= T::ZERO;
+                    if facing_same_direction {
+                        coplanar_front.push(polygon);
+                    } else {
+                        coplanar_back.push(polygon);
+                    }
+                }
+                Side::Front => front_polygons.push(polygon),
+                Side::Back => back_polygons.push(polygon),
+                Side::Straddling => {
+                    if let Some(front_part) = SutherlandHodgman3D::clip_polygon(&polygon, &[plane]) {
+                        front_polygons.push(front_part);
+                    }
+                    if let Some(back_part) = SutherlandHodgman3D::clip_polygon(&polygon, &[plane.flipped()]) {
+                        back_polygons.push(back_part);
+                    }
+                }
+            }
+        }
+
+        Some(BspNode {
+            plane,
+            coplanar_front,
+            coplanar_back,
+            front: BspNode::build(front_polygons).map(Box::new),
+            back: BspNode::build(back_polygons).map(Box::new),
+        })
+    }
+
+    /// Appends this subtree's polygons to `output` in back-to-front order as seen from `eye`.
+    fn front_to_back(&self, eye: &Point3<T>, output: &mut Vec<Polygon3<T>>) {
+        let eye_in_front = self.plane.is_inside(eye);
+        let (near, far) = if eye_in_front { (&self.front, &self.back) } else { (&self.back, &self.front) };
+        let (coplanar_near, coplanar_far) =
+            if eye_in_front { (&self.coplanar_front, &self.coplanar_back) } else { (&self.coplanar_back, &self.coplanar_front) };
+
+        if let Some(node) = far {
+            node.front_to_back(eye, output);
+        }
+
+        output.extend(coplanar_far.iter().cloned());
+        output.extend(coplanar_near.iter().cloned());
+
+        if let Some(node) = near {
+            node.front_to_back(eye, output);
+        }
+    }
+}
+
+/// Recursively partitions a set of coplanar-or-not polygons into a binary space partitioning
+/// tree, splitting any polygon that straddles a chosen splitting plane so every polygon
+/// stored in the tree lies entirely on one side of every ancestor plane above it.
+///
+/// This supports painter's-algorithm rendering: [`BspTree::front_to_back`] walks the tree
+/// relative to a given eye point and returns polygons back-to-front (farthest first), so
+/// drawing them in that order always lets nearer geometry overpaint farther geometry, without
+/// needing a z-buffer.
+pub(crate) struct BspTree<T: Coord> {
+    root: Option<BspNode<T>>,
+}
+
+impl<T: Coord> BspTree<T> {
+    pub(crate) fn build(polygons: Vec<Polygon3<T>>) -> Self {
+        BspTree { root: BspNode::build(polygons) }
+    }
+
+    pub(crate) fn front_to_back(&self, eye: &Point3<T>) -> Vec<Polygon3<T>> {
+        let mut output = vec![];
+        if let Some(root) = &self.root {
+            root.front_to_back(eye, &mut output);
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quad_at_z(z: f64) -> Polygon3<f64> {
+        Polygon3::new(vec![
+            Point3::new(0.0, 0.0, z),
+            Point3::new(1.0, 0.0, z),
+            Point3::new(1.0, 1.0, z),
+            Point3::new(0.0, 1.0, z),
+        ])
+    }
+
+    #[test]
+    fn farthest_polygon_is_drawn_first() {
+        let near = quad_at_z(5.0);
+        let far = quad_at_z(0.0);
+        let eye = Point3::new(0.5, 0.5, 10.0);
+
+        let tree = BspTree::build(vec![far, near]);
+        let ordered = tree.front_to_back(&eye);
+
+        assert_eq!(ordered.len(), 2);
+        assert_eq!(ordered[0].vertexes[0].z, 0.0);
+        assert_eq!(ordered[1].vertexes[0].z, 5.0);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::geometry::Coord;
+use crate::three_d::{Plane, Polygon3};
+
+/// Clips a polygon against a convex volume using the Sutherland-Hodgman algorithm, with each
+/// clip "edge" generalized to a clip plane.
+///
+/// Mirrors [`crate::clipping::SutherlandHodgman`] one dimension up: a point's `is_inside` test
+/// becomes the plane's signed-distance test, and an edge-plane crossing is found by
+/// [`Plane::intersection`] instead of [`crate::geometry::Line::intersection`]. Since the
+/// volume is convex, clipping can only ever keep or shrink the subject into a single output
+/// polygon (unlike [`crate::clipping::WeilerAtherton`], which can split it into several).
+pub(crate) struct SutherlandHodgman3D;
+
+impl SutherlandHodgman3D {
+    pub(crate) fn clip_polygon<T: Coord>(polygon: &Polygon3<T>, planes: &[Plane<T>]) -> Option<Polygon3<T>> {
+        let mut output_polygon = polygon.vertexes.clone();
+
+        for plane in planes {
+            if output_polygon.is_empty() {
+                break;
+            }
+
+            let mut new_vertexes = vec![];
+            for i in 0..output_polygon.len() {
+                let start = output_polygon[i];
+                let end = output_polygon[(i + 1) % output_polygon.len()];
+
+                let start_inside = plane.is_inside(&start);
+                let end_inside = plane.is_inside(&end);
+
+                if start_inside && end_inside {
+                    new_vertexes.push(end);
+                } else if start_inside && !end_inside {
+                    if let Some(point) = plane.intersection(&start, &end) {
+                        new_vertexes.push(point);
+                    }
+                } else if !start_inside && end_inside {
+                    if let Some(point) = plane.intersection(&start, &end) {
+                        new_vertexes.push(point);
+                    }
+                    new_vertexes.push(end);
+                }
+            }
+
+            output_polygon = new_vertexes;
+        }
+
+        if output_polygon.is_empty() {
+            None
+        } else {
+            Some(Polygon3::new(output_polygon))
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::geometry::Coord;
+
+/// A point in 3-space, the 3D analogue of [`crate::geometry::Point`].
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Point3<T: Coord> {
+    pub(crate) x: T,
+    pub(crate) y: T,
+    pub(crate) z: T,
+}
+
+impl<T: Coord> Point3<T> {
+    pub(crate) fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub(crate) fn sub(&self, other: &Point3<T>) -> Point3<T> {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+
+    pub(crate) fn dot(&self, other: &Point3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub(crate) fn cross(&self, other: &Point3<T>) -> Point3<T> {
+        Point3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub(crate) fn negated(&self) -> Point3<T> {
+        Point3::new(T::ZERO - self.x, T::ZERO - self.y, T::ZERO - self.z)
+    }
+
+    pub(crate) fn lerp(&self, other: &Point3<T>, t: T) -> Point3<T> {
+        Point3::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+            self.z + (other.z - self.z) * t,
+        )
+    }
+}
+
+/// A plane in 3-space, given in point-normal form, used as a clip half-space: points where
+/// `signed_distance` is non-negative are "inside".
+///
+/// This is the 3D generalization of a clip edge in [`crate::geometry::Line`]: clipping a
+/// polygon against a convex volume means clipping it against each face's plane in turn.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Plane<T: Coord> {
+    pub(crate) normal: Point3<T>,
+    pub(crate) offset: T,
+}
+
+impl<T: Coord> Plane<T> {
+    pub(crate) fn new(normal: Point3<T>, offset: T) -> Self {
+        Plane { normal, offset }
+    }
+
+    /// Builds the plane containing `polygon`, oriented by its vertex winding (right-hand
+    /// rule over the first two edges).
+    pub(crate) fn from_polygon(polygon: &Polygon3<T>) -> Self {
+        let a = polygon.vertexes[0];
+        let b = polygon.vertexes[1];
+        let c = polygon.vertexes[2];
+
+        let normal = b.sub(&a).cross(&c.sub(&a));
+        let offset = T::ZERO - normal.dot(&a);
+
+        Plane::new(normal, offset)
+    }
+
+    pub(crate) fn signed_distance(&self, point: &Point3<T>) -> T {
+        self.normal.dot(point) + self.offset
+    }
+
+    pub(crate) fn is_inside(&self, point: &Point3<T>) -> bool {
+        self.signed_distance(point) >= T::ZERO
+    }
+
+    /// Flips the half-space this plane considers "inside", by negating both the normal and
+    /// the offset.
+    pub(crate) fn flipped(&self) -> Plane<T> {
+        Plane::new(self.normal.negated(), T::ZERO - self.offset)
+    }
+
+    /// Finds where the segment `start -> end` crosses this plane, via the parameter where
+    /// the signed distance reaches zero.
+    pub(crate) fn intersection(&self, start: &Point3<T>, end: &Point3<T>) -> Option<Point3<T>> {
+        let start_distance = self.signed_distance(start);
+        let end_distance = self.signed_distance(end);
+        let denominator = start_distance - end_distance;
+
+        if denominator == T::ZERO {
+            return None;
+        }
+
+        let t = start_distance / denominator;
+        Some(start.lerp(end, t))
+    }
+}
+
+/// A planar polygon in 3-space, the 3D analogue of [`crate::geometry::Polygon`].
+#[derive(Debug, Clone)]
+pub(crate) struct Polygon3<T: Coord> {
+    pub(crate) vertexes: Vec<Point3<T>>,
+}
+
+impl<T: Coord> Polygon3<T> {
+    pub(crate) fn new(vertexes: Vec<Point3<T>>) -> Self {
+        Polygon3 { vertexes }
+    }
+}
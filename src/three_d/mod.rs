@@ -0,0 +1,7 @@
+mod bsp;
+mod clipping;
+mod geometry;
+
+pub(crate) use bsp::BspTree;
+pub(crate) use clipping::SutherlandHodgman3D;
+pub(crate) use geometry::{Plane, Point3, Polygon3};